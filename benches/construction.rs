@@ -0,0 +1,190 @@
+//! Statistically rigorous benchmarks for the suffix array and LCP array
+//! construction algorithms.
+//!
+//! Unlike `main`'s single [`Instant::now`](std::time::Instant::now)
+//! measurement, every benchmark here runs a warmup phase followed by many
+//! timed repetitions and reports the mean, median and standard deviation of
+//! the elapsed time alongside throughput in MB/s and, where the routine
+//! under test produces one, the [`MemoryResult`](ti_project::suffix_array::
+//! result::MemoryResult) peak memory figure, so that results are comparable
+//! across runs and suitable for regression tracking.
+//!
+//! The corpus is a directory of input files, defaulting to
+//! `benches/corpus`, overridable via the `BENCH_CORPUS` environment
+//! variable. Each file is benchmarked with every index width
+//! (`u32`/`u64`/`usize`) so that the cost of wider indices is directly
+//! comparable.
+
+#![feature(test)]
+
+extern crate test;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use test::Bencher;
+use ti_project::prelude::*;
+
+const WARMUP_ITERS: usize = 3;
+const TIMED_ITERS: usize = 20;
+
+/// Summary statistics over a set of timed repetitions of a single routine.
+struct Stats {
+    mean: Duration,
+    median: Duration,
+    stddev: Duration,
+    throughput_mb_s: f64,
+    /// The routine's `MemoryResult` peak memory in bytes, if it tracks one.
+    memory: Option<usize>,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration], bytes: usize, memory: Option<usize>) -> Self {
+        samples.sort_unstable();
+
+        let n = samples.len() as u32;
+        let mean = samples.iter().sum::<Duration>() / n;
+        let median = samples[samples.len() / 2];
+
+        let variance = samples
+            .iter()
+            .map(|d| {
+                let delta = d.as_secs_f64() - mean.as_secs_f64();
+                delta * delta
+            })
+            .sum::<f64>()
+            / n as f64;
+        let stddev = Duration::from_secs_f64(variance.sqrt());
+
+        let throughput_mb_s = (bytes as f64 / (1 << 20) as f64) / mean.as_secs_f64();
+
+        Self { mean, median, stddev, throughput_mb_s, memory }
+    }
+
+    fn report(&self, name: &str) {
+        let memory = match self.memory {
+            Some(bytes) => format!("{:.2}MB", bytes as f64 / (1 << 20) as f64),
+            None => "n/a".to_owned(),
+        };
+        eprintln!(
+            "BENCH name={name}\tmean={:?}\tmedian={:?}\tstddev={:?}\tthroughput={:.2}MB/s\tpeak_memory={memory}",
+            self.mean, self.median, self.stddev, self.throughput_mb_s
+        );
+    }
+}
+
+/// Runs `f` through a warmup phase and [`TIMED_ITERS`] timed repetitions,
+/// reporting the resulting statistics (and `memory`, correlated from the
+/// routine's own [`MemoryResult`] where it produces one) under `name`.
+fn bench_routine<T>(name: &str, bytes: usize, memory: Option<usize>, mut f: impl FnMut() -> T) {
+    for _ in 0..WARMUP_ITERS {
+        std::hint::black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(TIMED_ITERS);
+    for _ in 0..TIMED_ITERS {
+        let before = Instant::now();
+        std::hint::black_box(f());
+        samples.push(before.elapsed());
+    }
+
+    Stats::from_samples(&mut samples, bytes, memory).report(name);
+}
+
+fn corpus_dir() -> PathBuf {
+    std::env::var("BENCH_CORPUS").map(PathBuf::from).unwrap_or_else(|_| {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("benches").join("corpus")
+    })
+}
+
+fn corpus_files() -> Vec<(String, Vec<u8>)> {
+    let dir = corpus_dir();
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_file())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            fs::read(entry.path()).ok().map(|bytes| (name, bytes))
+        })
+        .collect()
+}
+
+fn bench_sais<Idx: ArrayIndex>(b: &mut Bencher, index_name: &str) {
+    for (file, text) in corpus_files() {
+        let memory = sa::sais::<Idx>(&text).expect("construction failed").memory;
+        bench_routine(&format!("sais/{index_name}/{file}"), text.len(), Some(memory), || {
+            sa::sais::<Idx>(&text).expect("construction failed").value
+        });
+    }
+    b.iter(|| ());
+}
+
+#[bench]
+fn bench_sais_u32(b: &mut Bencher) { bench_sais::<u32>(b, "u32") }
+
+#[bench]
+fn bench_sais_u64(b: &mut Bencher) { bench_sais::<u64>(b, "u64") }
+
+#[bench]
+fn bench_sais_usize(b: &mut Bencher) { bench_sais::<usize>(b, "usize") }
+
+fn bench_lcp_naive<Idx: ArrayIndex>(b: &mut Bencher, index_name: &str) {
+    for (file, text) in corpus_files() {
+        let sa = sa::sais::<Idx>(&text).expect("construction failed").value;
+        bench_routine(&format!("lcp_naive/{index_name}/{file}"), text.len(), None, || {
+            lcp::naive(&sa)
+        });
+    }
+    b.iter(|| ());
+}
+
+#[bench]
+fn bench_lcp_naive_u32(b: &mut Bencher) { bench_lcp_naive::<u32>(b, "u32") }
+
+#[bench]
+fn bench_lcp_naive_u64(b: &mut Bencher) { bench_lcp_naive::<u64>(b, "u64") }
+
+#[bench]
+fn bench_lcp_naive_usize(b: &mut Bencher) { bench_lcp_naive::<usize>(b, "usize") }
+
+fn bench_lcp_kasai<Idx: ArrayIndex>(b: &mut Bencher, index_name: &str) {
+    for (file, text) in corpus_files() {
+        let sa = sa::sais::<Idx>(&text).expect("construction failed").value;
+        let isa = sa.inverse();
+        bench_routine(&format!("lcp_kasai/{index_name}/{file}"), text.len(), None, || {
+            lcp::kasai(&isa)
+        });
+    }
+    b.iter(|| ());
+}
+
+#[bench]
+fn bench_lcp_kasai_u32(b: &mut Bencher) { bench_lcp_kasai::<u32>(b, "u32") }
+
+#[bench]
+fn bench_lcp_kasai_u64(b: &mut Bencher) { bench_lcp_kasai::<u64>(b, "u64") }
+
+#[bench]
+fn bench_lcp_kasai_usize(b: &mut Bencher) { bench_lcp_kasai::<usize>(b, "usize") }
+
+fn bench_lcp_phi<Idx: ArrayIndex>(b: &mut Bencher, index_name: &str) {
+    for (file, text) in corpus_files() {
+        let sa = sa::sais::<Idx>(&text).expect("construction failed").value;
+        bench_routine(&format!("lcp_phi/{index_name}/{file}"), text.len(), None, || lcp::phi(&sa));
+    }
+    b.iter(|| ());
+}
+
+#[bench]
+fn bench_lcp_phi_u32(b: &mut Bencher) { bench_lcp_phi::<u32>(b, "u32") }
+
+#[bench]
+fn bench_lcp_phi_u64(b: &mut Bencher) { bench_lcp_phi::<u64>(b, "u64") }
+
+#[bench]
+fn bench_lcp_phi_usize(b: &mut Bencher) { bench_lcp_phi::<usize>(b, "usize") }
@@ -0,0 +1,150 @@
+//! A compact FM-index supporting backward-search occurrence counting without
+//! keeping the original text resident, built on top of a
+//! [`SuffixArray`]'s Burrows-Wheeler transform.
+
+use crate::index::ArrayIndex;
+use crate::suffix_array::result::MemoryResult;
+use crate::suffix_array::SuffixArray;
+
+/// How many BWT positions separate two consecutive `Occ` samples.
+///
+/// Smaller values trade memory for faster [`FmIndex::count`] queries; ranks
+/// between samples are always completed by scanning from the nearest one,
+/// so this only affects the time/space tradeoff, not correctness.
+const OCC_SAMPLE_RATE: usize = 32;
+
+const ALPHABET_SIZE: usize = u8::MAX as usize + 1;
+
+/// A Burrows-Wheeler/FM-index over a byte text, supporting [`count`](
+/// FmIndex::count) without retaining the original text.
+///
+/// [`SuffixArray::bwt`] wraps around at the start of the text rather than
+/// using an explicit end-of-text sentinel. When the text ends with a
+/// terminator symbol smaller than every other symbol in it, suffix order and
+/// rotation order agree, and [`count`](FmIndex::count) returns the number of
+/// occurrences of `pattern` among the text's *cyclic rotations*, which is the
+/// plain substring count for every pattern that does not contain the
+/// terminator. On any other text the result is neither of the two: a match
+/// that straddles the wraparound point may or may not be counted (for the
+/// text `bab`, `bb` is counted once while `bba` is not counted at all). For
+/// plain substring counting, append such a terminator to the text before
+/// building the [`SuffixArray`] this index is derived from.
+#[derive(Debug, Clone)]
+pub struct FmIndex {
+    bwt: Box<[u8]>,
+    c: [usize; ALPHABET_SIZE],
+    occ: Occ,
+}
+
+impl FmIndex {
+    /// Builds an `FmIndex` from a completed suffix array.
+    pub fn build<Idx: ArrayIndex>(sa: &SuffixArray<u8, Idx>) -> MemoryResult<Self> {
+        let mut builder = MemoryResult::builder();
+
+        let bwt = sa.bwt();
+        builder.add_values::<u8>(bwt.len());
+
+        let c = cumulative_counts(&bwt);
+        builder.add_values::<usize>(c.len());
+
+        let occ = Occ::build(&bwt, OCC_SAMPLE_RATE);
+        builder.add_values::<usize>(occ.samples.len() * ALPHABET_SIZE);
+
+        builder.build(Self { bwt, c, occ })
+    }
+
+    /// Compares [`FmIndex::count`] against a direct scan over `text`'s
+    /// cyclic rotations, for every pattern in `patterns`. `text` must be the
+    /// same text the suffix array this index was [`build`](FmIndex::build)
+    /// from was constructed over, and must end with a terminator as described
+    /// in the [`FmIndex`] docs; on other texts the two counts can differ.
+    #[cfg(feature = "verify")]
+    pub fn verify(&self, text: &[u8], patterns: &[&[u8]]) {
+        let n = text.len();
+        for &pattern in patterns {
+            let expected = (0..n)
+                .filter(|&start| (0..pattern.len()).all(|k| text[(start + k) % n] == pattern[k]))
+                .count();
+            assert_eq!(
+                self.count(pattern),
+                expected,
+                "FmIndex::count({pattern:?}) disagreed with a direct rotation scan"
+            );
+        }
+    }
+
+    /// Returns the number of occurrences of `pattern` among the text's
+    /// cyclic rotations, when the text ends with a terminator (see the
+    /// [`FmIndex`] docs for that condition, for when this coincides with
+    /// plain substring counting, and for the result on other texts).
+    ///
+    /// Implements LF-mapping backward search: starting from the full
+    /// interval `[0, len)`, each pattern character processed right-to-left
+    /// narrows the interval to the suffixes prefixed by the characters seen
+    /// so far, stopping early once the interval is empty.
+    pub fn count(&self, pattern: &[u8]) -> usize {
+        let mut lo = 0usize;
+        let mut hi = self.bwt.len();
+
+        for &c in pattern.iter().rev() {
+            if lo >= hi {
+                return 0;
+            }
+            lo = self.c[c as usize] + self.occ.rank(&self.bwt, c, lo);
+            hi = self.c[c as usize] + self.occ.rank(&self.bwt, c, hi);
+        }
+        hi - lo
+    }
+}
+
+/// For each symbol, the number of BWT/text symbols lexicographically
+/// smaller than it.
+fn cumulative_counts(bwt: &[u8]) -> [usize; ALPHABET_SIZE] {
+    let mut counts = [0usize; ALPHABET_SIZE];
+    for &b in bwt {
+        counts[b as usize] += 1;
+    }
+
+    let mut c = [0usize; ALPHABET_SIZE];
+    let mut sum = 0;
+    for (symbol, count) in counts.into_iter().enumerate() {
+        c[symbol] = sum;
+        sum += count;
+    }
+    c
+}
+
+/// Sampled occurrence counts, giving the number of times each symbol
+/// appears in `bwt[0..pos]` for `pos` a multiple of the sample rate; ranks
+/// at other positions are completed by scanning the BWT from the nearest
+/// sample.
+#[derive(Debug, Clone)]
+struct Occ {
+    rate: usize,
+    samples: Box<[[usize; ALPHABET_SIZE]]>,
+}
+
+impl Occ {
+    fn build(bwt: &[u8], rate: usize) -> Self {
+        let mut samples = Vec::with_capacity(bwt.len() / rate + 1);
+        let mut counts = [0usize; ALPHABET_SIZE];
+        samples.push(counts);
+
+        for (i, &b) in bwt.iter().enumerate() {
+            counts[b as usize] += 1;
+            if (i + 1) % rate == 0 {
+                samples.push(counts);
+            }
+        }
+
+        Self { rate, samples: samples.into_boxed_slice() }
+    }
+
+    /// Returns the number of occurrences of `symbol` in `bwt[0..pos]`.
+    fn rank(&self, bwt: &[u8], symbol: u8, pos: usize) -> usize {
+        let sample_index = pos / self.rate;
+        let from = sample_index * self.rate;
+        let sampled = self.samples[sample_index][symbol as usize];
+        sampled + bwt[from..pos].iter().filter(|&&b| b == symbol).count()
+    }
+}
@@ -0,0 +1,90 @@
+//! Constant-time longest-common-prefix queries between arbitrary suffixes,
+//! backed by a sparse-table range-minimum-query index over an LCP array.
+//!
+//! This is the key primitive for longest-common-substring, suffix-tree
+//! emulation and repeat detection, none of which can be built from
+//! [`lcp_array`](crate::lcp_array)'s construction routines alone, since
+//! those only give the lcp of *adjacent* suffix array ranks.
+
+use crate::index::ArrayIndex;
+use crate::suffix_array::result::{Builder, MemoryResult};
+use crate::suffix_array::InverseSuffixArray;
+
+/// Answers `lcp(i, j)`, the length of the longest common prefix of the
+/// suffixes starting at text positions `i` and `j`, in O(1).
+///
+/// Positions are mapped to suffix array ranks via the inverse suffix array,
+/// and the answer is the minimum of the LCP array over the ranks in
+/// between, which a sparse table answers in O(1) after an O(n log n) build.
+#[derive(Debug, Clone)]
+pub struct LcpRmq<Idx> {
+    isa: Box<[Idx]>,
+    /// `sparse[k][i]` holds the minimum of `lcp[i .. i + 2^k)`.
+    sparse: Vec<Box<[Idx]>>,
+}
+
+impl<Idx: ArrayIndex> LcpRmq<Idx> {
+    /// Builds the sparse table from an inverse suffix array and its
+    /// matching LCP array, where `lcp[r]` is the longest common prefix of
+    /// the suffixes at adjacent suffix array ranks `r - 1` and `r` (`lcp[0]`
+    /// is unused).
+    pub fn build<T>(isa: &InverseSuffixArray<'_, '_, T, Idx>, lcp: &[Idx]) -> MemoryResult<Self> {
+        let mut builder = MemoryResult::builder();
+        let len = lcp.len();
+
+        let levels = if len == 0 { 0 } else { len.ilog2() as usize + 1 };
+        let mut sparse: Vec<Box<[Idx]>> = Vec::with_capacity(levels);
+        sparse.push(lcp.into());
+        builder.add_values::<Idx>(len);
+
+        for k in 1..levels {
+            let width = 1usize << k;
+            let half = width >> 1;
+            let prev = &sparse[k - 1];
+            let row: Box<[Idx]> =
+                (0..=len - width).map(|i| prev[i].min(prev[i + half])).collect();
+            builder.add_values::<Idx>(row.len());
+            sparse.push(row);
+        }
+
+        let isa: Box<[Idx]> = isa.inner().into();
+        builder.add_values::<Idx>(isa.len());
+
+        builder.build(Self { isa, sparse })
+    }
+
+    /// Returns the longest common prefix of the suffixes at text positions
+    /// `i` and `j`.
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        if i == j {
+            return self.isa.len() - i;
+        }
+
+        let p = self.isa[i].as_();
+        let q = self.isa[j].as_();
+        self.range_min(p.min(q) + 1, p.max(q))
+    }
+
+    /// Returns the minimum of the (inclusive) LCP array range `[lo, hi]`.
+    fn range_min(&self, lo: usize, hi: usize) -> usize {
+        let k = (hi - lo + 1).ilog2() as usize;
+        let row = &self.sparse[k];
+        row[lo].min(row[hi + 1 - (1 << k)]).as_()
+    }
+
+    /// Compares the RMQ answer for every pair in `sample` against a direct,
+    /// character-by-character longest-common-prefix computation.
+    #[cfg(feature = "verify")]
+    pub fn verify<T: PartialEq>(&self, text: &crate::text::Text<T>, sample: &[(usize, usize)]) {
+        use std::iter::zip;
+
+        for &(i, j) in sample {
+            let direct = zip(&text[i..], &text[j..]).take_while(|(a, b)| a == b).count();
+            assert_eq!(
+                self.lcp(i, j),
+                direct,
+                "LcpRmq::lcp({i}, {j}) disagreed with a direct comparison"
+            );
+        }
+    }
+}
@@ -1,11 +1,15 @@
+pub mod fm_index;
 pub mod lcp_array;
+pub mod lcp_rmq;
 pub mod num;
 pub mod sais;
 pub mod suffix_array;
 
 pub mod prelude {
+    pub use crate::fm_index::FmIndex;
     pub use crate::index::{ArrayIndex, ToIndex};
     pub use crate::lcp_array as lcp;
+    pub use crate::lcp_rmq::LcpRmq;
     pub use crate::num::*;
     pub use crate::suffix_array as sa;
 }
@@ -1,7 +1,10 @@
 mod sais;
+mod skew;
 
+use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::iter::zip;
+use std::ops::Range;
 
 use self::result::MemoryResult;
 use crate::index::{ArrayIndex, ToIndex};
@@ -21,6 +24,27 @@ pub fn sais<Idx: ArrayIndex>(text: &Text<u8>) -> MemoryResult<SuffixArray<u8, Id
     sais::sais(text)
 }
 
+/// Constructs a suffix array in `O(n + σ)` time, `σ` being the largest symbol
+/// value of the text, using the DC3/skew algorithm of Kärkkäinen and Sanders,
+/// which works over arbitrary integer alphabets (unlike [`sais`], which is
+/// specialized to `u8` texts).
+pub fn skew<T: ArrayIndex + Debug, Idx: ArrayIndex>(
+    text: &Text<T>,
+) -> MemoryResult<SuffixArray<T, Idx>> {
+    let result = skew::skew(text);
+
+    #[cfg(feature = "verify")]
+    {
+        result.value.verify(text);
+        assert!(
+            result.value.inner() == naive::<T, Idx>(text).inner(),
+            "skew construction disagreed with naive construction"
+        );
+    }
+
+    result
+}
+
 /// Represents an owned suffix array for a text. Additionally stores a reference
 /// to the original text.
 ///
@@ -83,6 +107,232 @@ impl<'txt, T, Idx: ArrayIndex> SuffixArray<'txt, T, Idx> {
     }
 }
 
+impl<'txt, T: Ord, Idx: ArrayIndex> SuffixArray<'txt, T, Idx> {
+    /// Returns the contiguous range of suffix array positions whose
+    /// suffixes are prefixed by `pattern`.
+    ///
+    /// This is a single-shot plain comparison binary search, `O(m log n)`.
+    /// Callers that search the same suffix array repeatedly should instead
+    /// build an [`LcpTables`] once with [`LcpTables::build`] and reuse it
+    /// via [`SuffixArray::search_with`], which is `O(m + log n)` per search.
+    /// [`LcpTables::build`] itself compares suffixes directly for every table
+    /// entry, which costs up to `O(n^2)` (e.g. on a text of one repeated
+    /// symbol), so it only pays off when amortized across multiple searches.
+    pub fn search(&self, pattern: &[T]) -> Range<usize> {
+        self.search_impl(pattern, None)
+    }
+
+    /// Same as [`SuffixArray::search`], but reuses a precomputed
+    /// [`LcpTables`] to accelerate the search to `O(m + log n)`, instead of
+    /// rebuilding it for every call.
+    pub fn search_with(&self, pattern: &[T], tables: &LcpTables) -> Range<usize> {
+        self.search_impl(pattern, Some(tables))
+    }
+
+    fn search_impl(&self, pattern: &[T], tables: Option<&LcpTables>) -> Range<usize> {
+        let len = self.sa.len();
+        if len == 0 || pattern.is_empty() {
+            return 0..len;
+        }
+
+        let lo = self.bound(pattern, tables, Ordering::Less);
+        let hi = self.bound(pattern, tables, Ordering::Greater);
+        lo..hi.max(lo)
+    }
+
+    /// Returns the number of suffixes prefixed by `pattern`.
+    pub fn count(&self, pattern: &[T]) -> usize { self.search(pattern).len() }
+
+    /// Returns an iterator over the text positions of all suffixes prefixed
+    /// by `pattern`.
+    pub fn locate(&self, pattern: &[T]) -> impl Iterator<Item = usize> + '_ {
+        self.search(pattern).map(|i| self.sa[i].as_())
+    }
+
+    /// Compares [`SuffixArray::search`] against a brute-force linear scan,
+    /// for every pattern in `patterns`.
+    #[cfg(feature = "verify")]
+    pub fn verify_search(&self, patterns: &[&[T]])
+    where
+        T: Debug,
+    {
+        for &pattern in patterns {
+            let matches: Vec<usize> =
+                (0..self.sa.len()).filter(|&i| self.suffix(i).starts_with(pattern)).collect();
+            let expected = match (matches.first(), matches.last()) {
+                (Some(&lo), Some(&hi)) => lo..hi + 1,
+                _ => 0..0,
+            };
+            assert_eq!(
+                self.search(pattern),
+                expected,
+                "SuffixArray::search({pattern:?}) disagreed with a brute-force scan"
+            );
+        }
+    }
+
+    fn suffix(&self, i: usize) -> &[T] { &self.text[self.sa[i].as_()..] }
+
+    /// Finds the leftmost suffix array position `i` at which
+    /// `pattern.cmp(suffix(i))` is no longer [`Ordering::Greater`].
+    ///
+    /// Passing [`Ordering::Less`] as `boundary` finds the lower bound of the
+    /// matching range; passing [`Ordering::Greater`] finds its upper bound,
+    /// by treating a suffix that merely extends `pattern` (i.e. still
+    /// matches it) as "not yet greater", so the search advances past every
+    /// match before stopping.
+    ///
+    /// This is the accelerated binary search of Manber & Myers (1993): `l`
+    /// and `r` track the lcp of `pattern` with the current lower/upper
+    /// bound suffix. When `tables` is given, most recursion steps skip
+    /// straight to comparing from `min(l, r)` instead of re-comparing from
+    /// scratch; without it, this falls back to a plain comparison binary
+    /// search (still reusing `min(l, r)` as a skip hint).
+    fn bound(&self, pattern: &[T], tables: Option<&LcpTables>, boundary: Ordering) -> usize {
+        let len = self.sa.len();
+
+        let cmp = |suffix: &[T], skip: usize| -> (usize, Ordering) {
+            let p = &pattern[skip.min(pattern.len())..];
+            let s = &suffix[skip.min(suffix.len())..];
+            let common = zip(p, s).take_while(|(a, b)| a == b).count();
+            let ord = match (common == p.len(), common == s.len()) {
+                (true, _) => boundary,
+                (false, true) => Ordering::Greater,
+                (false, false) => p[common].cmp(&s[common]),
+            };
+            (skip + common, ord)
+        };
+
+        let (mut l, ord_lo) = cmp(self.suffix(0), 0);
+        if ord_lo != Ordering::Greater {
+            return 0;
+        }
+        let (mut r, ord_hi) = cmp(self.suffix(len - 1), 0);
+        if ord_hi == Ordering::Greater {
+            return len;
+        }
+
+        let mut lo = 0;
+        let mut hi = len - 1;
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            let (mlcp, ord) = match tables {
+                None => cmp(self.suffix(mid), l.min(r)),
+                Some(tables) if l >= r => {
+                    if tables.llcp[mid] >= l {
+                        cmp(self.suffix(mid), l)
+                    } else {
+                        (tables.llcp[mid], Ordering::Less)
+                    }
+                }
+                Some(tables) => {
+                    if tables.rlcp[mid] >= r {
+                        cmp(self.suffix(mid), r)
+                    } else {
+                        (tables.rlcp[mid], Ordering::Greater)
+                    }
+                }
+            };
+
+            if ord == Ordering::Greater {
+                lo = mid;
+                l = mlcp;
+            } else {
+                hi = mid;
+                r = mlcp;
+            }
+        }
+        hi
+    }
+}
+
+impl<'txt, Idx: ArrayIndex> SuffixArray<'txt, u8, Idx> {
+    /// Returns the Burrows–Wheeler transform of the text, derived directly
+    /// from the suffix array as `bwt[i] = text[sa[i] - 1]`, wrapping around
+    /// to the last text symbol when `sa[i] == 0`.
+    ///
+    /// Because this wraps around rather than relying on an end-of-text
+    /// sentinel, the result is the transform of the text's sorted rotations
+    /// (and so invertible) only when `text` already ends with a symbol
+    /// smaller than every other symbol in it; on other texts it need not be
+    /// the transform of any rotation of the text (for `bab` it is `bab`,
+    /// while the rotations give `bba`). See [`crate::fm_index::FmIndex`] for
+    /// the consequence this has for occurrence counting.
+    pub fn bwt(&self) -> Box<[u8]> {
+        let text = self.text;
+        self.sa
+            .iter()
+            .map(|sa_i| {
+                let i = sa_i.as_();
+                if i == 0 { text[text.len() - 1] } else { text[i - 1] }
+            })
+            .collect()
+    }
+
+    /// Checks that [`bwt`](SuffixArray::bwt) is a permutation of the text's
+    /// symbols, a necessary (if not sufficient) condition for correctness.
+    #[cfg(feature = "verify")]
+    pub fn verify_bwt(&self) {
+        let mut counts = [0usize; 256];
+        for &b in &self.bwt() {
+            counts[b as usize] += 1;
+        }
+
+        let mut expected = [0usize; 256];
+        for i in 0..self.text.len() {
+            expected[self.text[i] as usize] += 1;
+        }
+
+        assert_eq!(counts, expected, "SuffixArray::bwt is not a permutation of the text");
+    }
+}
+
+/// Precomputed longest-common-prefix information between suffix array
+/// midpoints, used to accelerate [`SuffixArray::search_with`].
+///
+/// `llcp[mid]`/`rlcp[mid]` give the lcp of the suffix at `mid` with the
+/// suffixes at the lower/upper bound of the binary-search range for which
+/// `mid` is the midpoint. Because that range depends only on the position
+/// `mid` (not on the pattern being searched for), these tables can be built
+/// once and reused across many [`SuffixArray::search_with`] calls.
+#[derive(Debug, Clone)]
+pub struct LcpTables {
+    llcp: Box<[usize]>,
+    rlcp: Box<[usize]>,
+}
+
+impl LcpTables {
+    /// Builds the tables for the given suffix array.
+    pub fn build<T: Ord, Idx: ArrayIndex>(sa: &SuffixArray<T, Idx>) -> Self {
+        let len = sa.sa.len();
+        let mut llcp = vec![0; len];
+        let mut rlcp = vec![0; len];
+
+        fn fill<T: Ord, Idx: ArrayIndex>(
+            sa: &SuffixArray<T, Idx>,
+            lo: usize,
+            hi: usize,
+            llcp: &mut [usize],
+            rlcp: &mut [usize],
+        ) {
+            if hi - lo < 2 {
+                return;
+            }
+            let mid = lo + (hi - lo) / 2;
+            llcp[mid] = zip(sa.suffix(lo), sa.suffix(mid)).take_while(|(a, b)| a == b).count();
+            rlcp[mid] = zip(sa.suffix(mid), sa.suffix(hi)).take_while(|(a, b)| a == b).count();
+            fill(sa, lo, mid, llcp, rlcp);
+            fill(sa, mid, hi, llcp, rlcp);
+        }
+
+        if len > 1 {
+            fill(sa, 0, len - 1, &mut llcp, &mut rlcp);
+        }
+
+        Self { llcp: llcp.into_boxed_slice(), rlcp: rlcp.into_boxed_slice() }
+    }
+}
+
 /// Represents an inverse suffix array for a text. Additionally stores a
 /// reference to a suffix array of the original text.
 ///
@@ -0,0 +1,165 @@
+//! The DC3/skew suffix array construction algorithm of Kärkkäinen and
+//! Sanders ("Simple Linear Work Suffix Array Construction"), which runs in
+//! `O(n + σ)` time over arbitrary integer alphabets, `σ` being the largest
+//! symbol value (each radix pass counts over `0..=σ`), by recursing on a
+//! sample of at most `2/3` of the suffixes.
+
+use super::result::{Builder, MemoryResult};
+use super::SuffixArray;
+use crate::index::ArrayIndex;
+use crate::text::Text;
+
+pub fn skew<T: ArrayIndex, Idx: ArrayIndex>(text: &Text<T>) -> MemoryResult<SuffixArray<T, Idx>> {
+    let mut builder = MemoryResult::builder();
+
+    let symbols: Vec<usize> = text.iter().map(|c| c.as_() + 1).collect();
+    builder.add_values::<usize>(symbols.len());
+
+    let alphabet_size = symbols.iter().copied().max().unwrap_or(0);
+    let sa = suffix_array(&symbols, alphabet_size, &mut builder);
+
+    builder.build(SuffixArray { text, sa: sa.into_iter().map(Idx::from_usize).collect() })
+}
+
+/// Computes the suffix array of `s`, an alphabet-`0..=alphabet_size` string
+/// that reserves `0` as an end-of-string sentinel.
+fn suffix_array<B>(s: &[usize], alphabet_size: usize, builder: &mut Builder<B>) -> Vec<usize> {
+    let n = s.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    if n == 1 {
+        return vec![0];
+    }
+
+    // `sym` treats positions past the end of `s` as the sentinel `0`,
+    // standing in for the explicit padding used by the textbook
+    // presentation of the algorithm.
+    let sym = |i: usize| s.get(i).copied().unwrap_or(0);
+
+    let n0 = (n + 2) / 3;
+    let n1 = (n + 1) / 3;
+    let n2 = n / 3;
+    let n02 = n0 + n2;
+
+    // The sample positions `i % 3 != 0`, as triples `(s[i], s[i+1], s[i+2])`.
+    let sample: Vec<usize> = (0..n + (n0 - n1)).filter(|i| i % 3 != 0).collect();
+    builder.add_values::<usize>(sample.len());
+
+    let ranked = radix_pass(&sample, alphabet_size, |i| sym(i + 2));
+    let ranked = radix_pass(&ranked, alphabet_size, |i| sym(i + 1));
+    let ranked = radix_pass(&ranked, alphabet_size, sym);
+    builder.add_values::<usize>(3 * ranked.len());
+
+    // Assign lexicographic ranks to the sampled triples, writing them into
+    // `s12` at `i/3` (for `i % 3 == 1`) or `i/3 + n0` (for `i % 3 == 2`), so
+    // that `s12` holds the reduced string in text order.
+    let mut s12 = vec![0usize; n02 + 3];
+    let mut name = 0usize;
+    let mut last = (usize::MAX, usize::MAX, usize::MAX);
+    for &i in &ranked {
+        let triple = (sym(i), sym(i + 1), sym(i + 2));
+        if triple != last {
+            name += 1;
+            last = triple;
+        }
+        if i % 3 == 1 {
+            s12[i / 3] = name;
+        } else {
+            s12[i / 3 + n0] = name;
+        }
+    }
+    builder.add_values::<usize>(s12.len());
+
+    let mut sa12 = if name < n02 {
+        // The sampled triples were not all distinct: recurse on the string
+        // of ranks, which is at most `2/3` the length of `s`.
+        let order = suffix_array(&s12[..n02], name, builder);
+        for (rank, &i) in order.iter().enumerate() {
+            s12[i] = rank + 1;
+        }
+        order
+    } else {
+        // The ranks were already a permutation of `1..=n02`: read off their
+        // order directly, without recursing.
+        let mut sa12 = vec![0usize; n02];
+        for (i, &rank) in s12[..n02].iter().enumerate() {
+            sa12[rank - 1] = i;
+        }
+        sa12
+    };
+    builder.add_values::<usize>(sa12.len());
+
+    // Translate the `s12`-space positions back into positions of `s`.
+    for rank in sa12.iter_mut() {
+        *rank = if *rank < n0 { *rank * 3 + 1 } else { (*rank - n0) * 3 + 2 };
+    }
+
+    // Sort the non-sample suffixes (`i % 3 == 0`) by `(s[i], rank[i+1])`,
+    // reusing the already-computed sample ranks via a single radix pass.
+    let non_sample: Vec<usize> = sa12.iter().filter(|&&i| i % 3 == 1).map(|&i| i - 1).collect();
+    let sa0 = radix_pass(&non_sample, alphabet_size, sym);
+    builder.add_values::<usize>(sa0.len());
+
+    let rank12 = |i: usize| -> usize {
+        if i >= n {
+            0
+        } else if i % 3 == 1 {
+            s12[i / 3]
+        } else {
+            s12[i / 3 + n0]
+        }
+    };
+
+    // Merge the sorted sample (`sa12`) and non-sample (`sa0`) suffixes.
+    // Because one of the two positions being compared always falls on a
+    // sampled rank, each comparison is `O(1)`.
+    //
+    // When `n0 > n1` (text length `== 1 mod 3`), `sa12[0]` is the padding
+    // suffix at the out-of-range position `n`, which sorts first; `t` starts
+    // past it so the merge only ever emits the `n` real text positions.
+    let mut sa = Vec::with_capacity(n);
+    let (mut p, mut t) = (0, n0 - n1);
+    while p < sa0.len() && t < sa12.len() {
+        let i = sa12[t];
+        let j = sa0[p];
+        let i_le_j = if i % 3 == 1 {
+            (sym(i), rank12(i + 1)) <= (sym(j), rank12(j + 1))
+        } else {
+            (sym(i), sym(i + 1), rank12(i + 2)) <= (sym(j), sym(j + 1), rank12(j + 2))
+        };
+        if i_le_j {
+            sa.push(i);
+            t += 1;
+        } else {
+            sa.push(j);
+            p += 1;
+        }
+    }
+    sa.extend_from_slice(&sa12[t..]);
+    sa.extend_from_slice(&sa0[p..]);
+    sa
+}
+
+/// A single counting-sort pass over `indices`, keyed by `key`.
+fn radix_pass(indices: &[usize], alphabet_size: usize, key: impl Fn(usize) -> usize) -> Vec<usize> {
+    let mut count = vec![0usize; alphabet_size + 2];
+    for &i in indices {
+        count[key(i)] += 1;
+    }
+
+    let mut sum = 0;
+    for c in count.iter_mut() {
+        let current = *c;
+        *c = sum;
+        sum += current;
+    }
+
+    let mut out = vec![0usize; indices.len()];
+    for &i in indices {
+        let k = key(i);
+        out[count[k]] = i;
+        count[k] += 1;
+    }
+    out
+}